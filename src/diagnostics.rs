@@ -0,0 +1,105 @@
+//! Human-readable diagnostics for pest parse errors.
+//!
+//! pest already computes the offending line/column and can render a caret-annotated snippet
+//! (`--> 1:1`, `^---`, "unexpected digit", ...); this module just teaches it our rule names via
+//! [`pest::error::Error::renamed_rules`] and attaches the source filename via
+//! [`pest::error::Error::with_path`], so a failure reads like a normal compiler diagnostic.
+
+use pest::error::Error;
+
+use crate::Rule;
+
+/// Severity of a [`Diagnostic`], so editors can colour/filter them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Severity {
+    Error,
+}
+
+/// A structured, editor-friendly rendering of a [`pest::error::Error`].
+#[derive(Debug, Clone)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+/// Map a [`Rule`] variant to the name used in diagnostics and error messages.
+///
+/// Grammar rules worth a dedicated, human phrase are listed explicitly; anything else falls back
+/// to its `Debug` name so this stays in sync as `verus.pest` grows.
+pub fn rule_name(rule: Rule) -> String {
+    match rule {
+        Rule::file => "file".to_string(),
+        Rule::function => "function".to_string(),
+        Rule::fn_qualifier => "function qualifier".to_string(),
+        Rule::ident => "identifier".to_string(),
+        Rule::param_list | Rule::param => "parameter".to_string(),
+        Rule::type_expr => "type".to_string(),
+        Rule::ret_clause => "return clause".to_string(),
+        Rule::spec_clause => "spec clause".to_string(),
+        Rule::requires_clause => "requires clause".to_string(),
+        Rule::ensures_clause => "ensures clause".to_string(),
+        Rule::invariant_clause => "invariant clause".to_string(),
+        Rule::decreases_clause => "decreases clause".to_string(),
+        Rule::expr_list => "expression list".to_string(),
+        Rule::block => "block".to_string(),
+        Rule::stmt => "statement".to_string(),
+        Rule::expr | Rule::expr_inner => "expression".to_string(),
+        Rule::quantifier_expr | Rule::quantifier_kw => "quantifier expression".to_string(),
+        Rule::binder_list | Rule::binder => "binder".to_string(),
+        Rule::atom => "atom".to_string(),
+        Rule::call_args => "call arguments".to_string(),
+        Rule::literal => "literal".to_string(),
+        Rule::prefix_op => "prefix operator".to_string(),
+        Rule::bin_op => "binary operator".to_string(),
+        Rule::EOI => "end of file".to_string(),
+        other => format!("{other:?}"),
+    }
+}
+
+/// Build a structured [`Diagnostic`] out of a raw pest error.
+pub fn diagnostic(error: &Error<Rule>) -> Diagnostic {
+    use pest::error::LineColLocation;
+
+    let (start, end) = match error.line_col {
+        LineColLocation::Pos(pos) => (pos, pos),
+        LineColLocation::Span(start, end) => (start, end),
+    };
+    Diagnostic {
+        severity: Severity::Error,
+        message: error.variant.message().to_string(),
+        start,
+        end,
+    }
+}
+
+/// Render a full multi-line diagnostic: filename, source line, caret span, and expected rules.
+pub fn render(filename: &str, error: Error<Rule>) -> String {
+    error
+        .renamed_rules(|rule| rule_name(*rule))
+        .with_path(filename)
+        .to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::VerusParser;
+    use pest::Parser;
+
+    #[test]
+    fn rule_name_maps_known_rules() {
+        assert_eq!(rule_name(Rule::file), "file");
+        assert_eq!(rule_name(Rule::expr), "expression");
+        assert_eq!(rule_name(Rule::expr_inner), "expression");
+    }
+
+    #[test]
+    fn render_includes_filename_and_no_raw_rule_name() {
+        let error = VerusParser::parse(Rule::file, "123").unwrap_err();
+        let rendered = render("example.rs", error);
+        assert!(rendered.contains("example.rs"), "{rendered}");
+        assert!(!rendered.contains("expr_inner"), "{rendered}");
+    }
+}
@@ -0,0 +1,208 @@
+//! Typed syntax-tree API over the Verus pest grammar.
+//!
+//! [`VerusParser`] (via pest's [`Parser`] trait) produces raw
+//! [`Pairs`](pest::iterators::Pairs); this crate wraps that output in a [`SyntaxTree`] so
+//! downstream tools (formatters, linters) can walk a real tree with [`SyntaxNode::children`] and
+//! [`SyntaxNode::children_by_rule`] instead of re-flattening the iterator by hand every time.
+
+use pest::iterators::Pair;
+use pest::Parser;
+use pest_derive::Parser;
+
+// Turns out, pest_derive (for some unknown reason, on our grammar, but not on smaller examples)
+// requires us to specify `extern crate alloc`; since we're already in std land, this is perfectly
+// fine, but weird that it is needed.
+extern crate alloc;
+
+// `verus.pest` uses `#tag = rule` node tags, which need pest_derive's `grammar-extras` feature
+// turned on in Cargo.toml.
+
+pub mod diagnostics;
+pub mod trivia;
+
+use trivia::Comment;
+
+#[derive(Parser)]
+#[grammar = "verus.pest"]
+pub struct VerusParser;
+
+/// Result of a [`VerusParser`] call, boxing the error since `pest::error::Error` is large.
+pub type Result<T> = std::result::Result<T, Box<pest::error::Error<Rule>>>;
+
+/// Byte offsets plus 1-indexed line/column, matching what `pest::Position` reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+    pub start_line_col: (usize, usize),
+    pub end_line_col: (usize, usize),
+}
+
+/// A single node in a [`SyntaxTree`], carrying its [`Rule`], source span, and children.
+#[derive(Debug, Clone)]
+pub struct SyntaxNode {
+    rule: Rule,
+    tag: Option<String>,
+    span: Span,
+    text: String,
+    children: Vec<SyntaxNode>,
+}
+
+impl SyntaxNode {
+    fn from_pair(pair: Pair<'_, Rule>) -> Self {
+        let span = pair.as_span();
+        let start_line_col = span.start_pos().line_col();
+        let end_line_col = span.end_pos().line_col();
+        SyntaxNode {
+            rule: pair.as_rule(),
+            tag: pair.as_node_tag().map(str::to_string),
+            span: Span {
+                start: span.start(),
+                end: span.end(),
+                start_line_col,
+                end_line_col,
+            },
+            text: pair.as_str().to_string(),
+            children: pair.into_inner().map(SyntaxNode::from_pair).collect(),
+        }
+    }
+
+    /// The grammar rule that produced this node.
+    pub fn rule(&self) -> Rule {
+        self.rule
+    }
+
+    /// This node's source span.
+    pub fn span(&self) -> Span {
+        self.span
+    }
+
+    /// The exact source text this node was parsed from.
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// This node's direct children, in source order.
+    pub fn children(&self) -> &[SyntaxNode] {
+        &self.children
+    }
+
+    /// Direct children matching `rule`.
+    pub fn children_by_rule(&self, rule: Rule) -> impl Iterator<Item = &SyntaxNode> {
+        self.children.iter().filter(move |child| child.rule == rule)
+    }
+
+    /// All descendants (at any depth) matching `rule`, in source order.
+    pub fn descendants_by_rule(&self, rule: Rule) -> Vec<&SyntaxNode> {
+        let mut out = Vec::new();
+        self.collect_descendants_by_rule(rule, &mut out);
+        out
+    }
+
+    fn collect_descendants_by_rule<'a>(&'a self, rule: Rule, out: &mut Vec<&'a SyntaxNode>) {
+        for child in &self.children {
+            if child.rule == rule {
+                out.push(child);
+            }
+            child.collect_descendants_by_rule(rule, out);
+        }
+    }
+
+    /// The `#tag = rule` name this node was captured under, if `verus.pest` tagged it (e.g.
+    /// `"requires"`, `"body"`).
+    pub fn tag(&self) -> Option<&str> {
+        self.tag.as_deref()
+    }
+
+    /// The first descendant (searched depth-first, including this node) tagged `tag` in
+    /// `verus.pest`, e.g. `function_node.tagged("requires")`.
+    pub fn tagged(&self, tag: &str) -> Option<&SyntaxNode> {
+        if self.tag.as_deref() == Some(tag) {
+            return Some(self);
+        }
+        self.children.iter().find_map(|child| child.tagged(tag))
+    }
+}
+
+/// A parsed Verus source file: the [`SyntaxNode`] rooted at `Rule::file`.
+#[derive(Debug, Clone)]
+pub struct SyntaxTree {
+    root: SyntaxNode,
+    comments: Vec<Comment>,
+}
+
+impl SyntaxTree {
+    /// The root node (always a `Rule::file` node).
+    pub fn root(&self) -> &SyntaxNode {
+        &self.root
+    }
+
+    /// Comments found in the source, if this tree was built with [`parse_file_with_trivia`].
+    /// Empty for a tree built with plain [`parse_file`], since `COMMENT` is a silent grammar rule
+    /// and its text never reaches the [`SyntaxNode`] tree.
+    pub fn comments(&self) -> &[Comment] {
+        &self.comments
+    }
+}
+
+/// Parse a whole Verus source file into a [`SyntaxTree`].
+pub fn parse_file(source: &str) -> Result<SyntaxTree> {
+    let root = parse_root(source)?;
+    Ok(SyntaxTree {
+        root,
+        comments: Vec::new(),
+    })
+}
+
+/// Parse a whole Verus source file into a [`SyntaxTree`], additionally scanning the source for
+/// comments so a round-tripping tool (e.g. a formatter) never loses them. This is the opt-in,
+/// comment-preserving counterpart to [`parse_file`].
+pub fn parse_file_with_trivia(source: &str) -> Result<SyntaxTree> {
+    let root = parse_root(source)?;
+    Ok(SyntaxTree {
+        root,
+        comments: trivia::scan_comments(source),
+    })
+}
+
+fn parse_root(source: &str) -> Result<SyntaxNode> {
+    let mut pairs = VerusParser::parse(Rule::file, source).map_err(Box::new)?;
+    let root_pair = pairs
+        .next()
+        .expect("Rule::file always produces exactly one pair");
+    Ok(SyntaxNode::from_pair(root_pair))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_file_builds_typed_tree() {
+        let tree = parse_file("fn f(x: int) { x; }").unwrap();
+
+        assert_eq!(tree.root().rule(), Rule::file);
+        let function = tree
+            .root()
+            .children_by_rule(Rule::function)
+            .next()
+            .expect("one function");
+        assert_eq!(function.text(), "fn f(x: int) { x; }");
+
+        let exprs = tree.root().descendants_by_rule(Rule::expr);
+        assert_eq!(exprs.len(), 1);
+        assert_eq!(exprs[0].text(), "x");
+    }
+
+    #[test]
+    fn tagged_finds_requires_and_body() {
+        let tree = parse_file("proof fn f(x: int) requires x > 0 { x; }").unwrap();
+        let function = tree.root().children_by_rule(Rule::function).next().unwrap();
+
+        let requires = function.tagged("requires").expect("#requires tag");
+        assert_eq!(requires.rule(), Rule::expr_list);
+
+        let body = function.tagged("body").expect("#body tag");
+        assert_eq!(body.rule(), Rule::block);
+    }
+}
@@ -1,35 +1,216 @@
-use std::collections::BTreeSet;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
 
 use anyhow::anyhow;
-use pest::Parser;
-use pest_derive::Parser;
 
-// Turns out, pest_derive (for some unknown reason, on our grammar, but not on smaller examples)
-// requires us to specify `extern crate alloc`; since we're already in std land, this is perfectly
-// fine, but weird that it is needed.
-extern crate alloc;
+use verus_pest::{diagnostics, parse_file, Rule, SyntaxNode};
 
-#[derive(Parser)]
-#[grammar = "verus.pest"]
-pub struct VerusParser;
+/// Aggregate statistics gathered while parsing a batch of Verus files.
+#[derive(Debug, Default)]
+struct BatchStats {
+    files_ok: usize,
+    files_failed: usize,
+    rule_histogram: BTreeMap<String, usize>,
+    spec_bytes: usize,
+    exec_bytes: usize,
+}
+
+impl BatchStats {
+    fn add_file(&mut self, root: &SyntaxNode) {
+        self.files_ok += 1;
+        count_rules(root, &mut self.rule_histogram);
+        let (spec_bytes, exec_bytes) = spec_vs_exec_bytes(root);
+        self.spec_bytes += spec_bytes;
+        self.exec_bytes += exec_bytes;
+    }
+}
 
 fn main() -> anyhow::Result<()> {
-    let unparsed_file =
-        std::fs::read_to_string(std::env::args().nth(1).ok_or(anyhow!("need argument"))?)?;
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.is_empty() {
+        return Err(anyhow!("need at least one file or directory argument"));
+    }
+
+    let paths = collect_paths(&args)?;
 
-    let parsed = VerusParser::parse(Rule::file, &unparsed_file)?;
+    let mut stats = BatchStats::default();
+    let mut failures = Vec::new();
 
-    let parsed = parsed.flatten().collect::<Vec<_>>();
+    for path in &paths {
+        if let Err(rendered) = process_file(path, &mut stats) {
+            failures.push(rendered);
+        }
+    }
 
-    let parsed = parsed
-        .into_iter()
-        .filter(|p| matches!(p.as_rule(), Rule::expr_inner | Rule::expr))
-        .map(|p| p.as_str().trim())
-        .collect::<BTreeSet<_>>();
+    for rendered in &failures {
+        eprintln!("{rendered}\n");
+    }
+
+    print_summary(&paths, &stats);
+
+    Ok(())
+}
 
-    println!("{parsed:#?}");
+/// Parse one file and fold it into `stats`. On failure (unreadable file or parse error), `stats`
+/// is updated to record the failure and a rendered diagnostic is returned instead of aborting the
+/// whole batch.
+fn process_file(path: &Path, stats: &mut BatchStats) -> Result<(), String> {
+    let source = match std::fs::read_to_string(path) {
+        Ok(source) => source,
+        Err(err) => {
+            stats.files_failed += 1;
+            return Err(format!("could not read {}: {err}", path.display()));
+        }
+    };
+    match parse_file(&source) {
+        Ok(tree) => {
+            stats.add_file(tree.root());
+            Ok(())
+        }
+        Err(err) => {
+            stats.files_failed += 1;
+            Err(diagnostics::render(&path.display().to_string(), *err))
+        }
+    }
+}
 
-    dbg!(parsed.len());
+/// Expand `args` (files and/or directories) into a sorted list of `.rs` files to parse. A path
+/// named directly is always included, even without a `.rs` extension; directories are walked
+/// recursively and filtered to `.rs` files.
+fn collect_paths(args: &[String]) -> anyhow::Result<Vec<PathBuf>> {
+    let mut out = Vec::new();
+    for arg in args {
+        collect_paths_from(Path::new(arg), &mut out)?;
+    }
+    out.sort();
+    Ok(out)
+}
 
+fn collect_paths_from(path: &Path, out: &mut Vec<PathBuf>) -> anyhow::Result<()> {
+    if path.is_dir() {
+        for entry in std::fs::read_dir(path)? {
+            let entry = entry?.path();
+            if entry.is_dir() || entry.extension().is_some_and(|ext| ext == "rs") {
+                collect_paths_from(&entry, out)?;
+            }
+        }
+    } else {
+        out.push(path.to_path_buf());
+    }
     Ok(())
 }
+
+/// Count every node in `root`'s subtree by its raw grammar rule. Deliberately keyed by
+/// `Rule`'s `Debug` name rather than [`diagnostics::rule_name`]: the latter collapses related
+/// rules (e.g. `expr`/`expr_inner`, `quantifier_expr`/`quantifier_kw`) into one human-readable
+/// bucket for error messages, which would make these counts both inflated and ambiguous.
+fn count_rules(node: &SyntaxNode, histogram: &mut BTreeMap<String, usize>) {
+    *histogram.entry(format!("{:?}", node.rule())).or_insert(0) += 1;
+    for child in node.children() {
+        count_rules(child, histogram);
+    }
+}
+
+/// Split the source bytes covered by every function in `root` into spec (`proof`/`spec`) vs exec
+/// code, by its `fn_qualifier`s.
+fn spec_vs_exec_bytes(root: &SyntaxNode) -> (usize, usize) {
+    let mut spec_bytes = 0;
+    let mut exec_bytes = 0;
+    for function in root.descendants_by_rule(Rule::function) {
+        let is_spec = function
+            .children_by_rule(Rule::fn_qualifier)
+            .any(|qualifier| matches!(qualifier.text(), "proof" | "spec"));
+        let span = function.span();
+        let len = span.end - span.start;
+        if is_spec {
+            spec_bytes += len;
+        } else {
+            exec_bytes += len;
+        }
+    }
+    (spec_bytes, exec_bytes)
+}
+
+fn print_summary(paths: &[PathBuf], stats: &BatchStats) {
+    println!("parsed {} file(s): {} ok, {} failed", paths.len(), stats.files_ok, stats.files_failed);
+
+    println!("rule occurrences:");
+    for (rule, count) in &stats.rule_histogram {
+        println!("  {rule}: {count}");
+    }
+
+    let total_bytes = stats.spec_bytes + stats.exec_bytes;
+    if total_bytes > 0 {
+        let spec_pct = 100.0 * stats.spec_bytes as f64 / total_bytes as f64;
+        println!(
+            "spec vs exec code: {} spec bytes, {} exec bytes ({spec_pct:.1}% spec)",
+            stats.spec_bytes, stats.exec_bytes
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn add_file_splits_spec_and_exec_bytes() {
+        let tree = parse_file("proof fn f() {} fn g() {}").unwrap();
+        let mut stats = BatchStats::default();
+        stats.add_file(tree.root());
+
+        assert_eq!(stats.files_ok, 1);
+        assert!(stats.spec_bytes > 0);
+        assert!(stats.exec_bytes > 0);
+        assert_eq!(*stats.rule_histogram.get("function").unwrap(), 2);
+    }
+
+    #[test]
+    fn count_rules_does_not_collapse_distinct_rules() {
+        let tree =
+            parse_file("proof fn f(x: int) requires x > 0 ensures x > 0 { x; }").unwrap();
+        let mut histogram = BTreeMap::new();
+        count_rules(tree.root(), &mut histogram);
+
+        // `expr` and `expr_inner` must stay distinct buckets, not collapse into one
+        // "expression" count the way `diagnostics::rule_name` does for error messages.
+        assert!(histogram.contains_key("expr"));
+        assert!(histogram.contains_key("expr_inner"));
+        assert_ne!(histogram.get("expr"), histogram.get("expr_inner"));
+    }
+
+    #[test]
+    fn unreadable_file_is_recorded_as_a_failure_not_an_abort() {
+        let mut stats = BatchStats::default();
+        let missing = PathBuf::from("/no/such/file-for-verus-pest-tests.rs");
+
+        let result = process_file(&missing, &mut stats);
+
+        assert!(result.is_err());
+        assert_eq!(stats.files_failed, 1);
+        assert_eq!(stats.files_ok, 0);
+    }
+
+    #[test]
+    fn batch_keeps_going_past_a_bad_file() {
+        let dir = std::env::temp_dir().join("verus-pest-test-batch");
+        std::fs::create_dir_all(&dir).unwrap();
+        let good = dir.join("good.rs");
+        std::fs::write(&good, "fn f() {}").unwrap();
+        let missing = dir.join("does-not-exist.rs");
+
+        let mut stats = BatchStats::default();
+        let mut failures = Vec::new();
+        for path in [&good, &missing] {
+            if let Err(rendered) = process_file(path, &mut stats) {
+                failures.push(rendered);
+            }
+        }
+
+        assert_eq!(stats.files_ok, 1);
+        assert_eq!(stats.files_failed, 1);
+        assert_eq!(failures.len(), 1);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+//! Opt-in comment-preserving pass over Verus source.
+//!
+//! `WHITESPACE` and `COMMENT` in `verus.pest` are silent rules, so a normal [`crate::parse_file`]
+//! drops comment text entirely — fine for extracting expressions, fatal for a formatter that
+//! must round-trip. [`scan_comments`] walks the raw source once, independent of the main parse,
+//! and returns every comment's span and text so a caller can merge it back into a
+//! [`SyntaxTree`](crate::SyntaxTree).
+
+/// A single `//` line comment or `/* */` block comment found in source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Comment {
+    pub start: usize,
+    pub end: usize,
+    pub text: String,
+}
+
+/// Find every comment in `source`, in source order.
+pub fn scan_comments(source: &str) -> Vec<Comment> {
+    let mut comments = Vec::new();
+    let mut rest = source;
+    let mut offset = 0;
+    while !rest.is_empty() {
+        if let Some(after) = rest.strip_prefix("//") {
+            let len = 2 + after.find('\n').unwrap_or(after.len());
+            comments.push(Comment {
+                start: offset,
+                end: offset + len,
+                text: rest[..len].to_string(),
+            });
+            offset += len;
+            rest = &rest[len..];
+        } else if rest.starts_with("/*") {
+            let len = block_comment_len(rest);
+            comments.push(Comment {
+                start: offset,
+                end: offset + len,
+                text: rest[..len].to_string(),
+            });
+            offset += len;
+            rest = &rest[len..];
+        } else {
+            let step = rest.chars().next().map_or(1, char::len_utf8);
+            offset += step;
+            rest = &rest[step..];
+        }
+    }
+    comments
+}
+
+/// Length in bytes of the (possibly nested) `/* ... */` block comment `text` starts with.
+fn block_comment_len(text: &str) -> usize {
+    let mut depth = 0usize;
+    let mut rest = text;
+    let mut len = 0;
+    loop {
+        if let Some(after) = rest.strip_prefix("/*") {
+            depth += 1;
+            len += 2;
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("*/") {
+            depth -= 1;
+            len += 2;
+            rest = after;
+            if depth == 0 {
+                return len;
+            }
+        } else if rest.is_empty() {
+            return len;
+        } else {
+            let step = rest.chars().next().map_or(1, char::len_utf8);
+            len += step;
+            rest = &rest[step..];
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_line_and_block_comments() {
+        let source = "fn f() {} // trailing\n/* block */\nfn g() {}";
+        let comments = scan_comments(source);
+
+        assert_eq!(comments.len(), 2);
+        assert_eq!(comments[0].text, "// trailing");
+        assert_eq!(&source[comments[0].start..comments[0].end], "// trailing");
+        assert_eq!(comments[1].text, "/* block */");
+        assert_eq!(&source[comments[1].start..comments[1].end], "/* block */");
+    }
+
+    #[test]
+    fn handles_nested_block_comments() {
+        let source = "/* outer /* inner */ still outer */";
+        let comments = scan_comments(source);
+
+        assert_eq!(comments.len(), 1);
+        assert_eq!(comments[0].text, source);
+    }
+
+    #[test]
+    fn parse_file_with_trivia_retains_comments() {
+        let tree = crate::parse_file_with_trivia("fn f() {} // hi\n").unwrap();
+        assert_eq!(tree.comments().len(), 1);
+        assert_eq!(tree.comments()[0].text, "// hi");
+
+        let tree = crate::parse_file("fn f() {} // hi\n").unwrap();
+        assert!(tree.comments().is_empty());
+    }
+}